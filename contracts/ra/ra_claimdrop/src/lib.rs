@@ -1,13 +1,16 @@
 use cosmwasm_std::{
     entry_point, to_json_binary, Addr, BankMsg, Binary, Coin, Deps, DepsMut, Env, MessageInfo,
-    Response, StdError, StdResult, Uint128,
+    Order, Response, StdError, StdResult, Uint128,
 };
-use cw2::set_contract_version;
-use cw_storage_plus::{Item, Map};
+use cw2::{get_contract_version, set_contract_version};
+use cw_storage_plus::{Bound, Item, Map};
 use serde::{Deserialize, Serialize};
 
 const CONTRACT_NAME: &str = "ra_claimdrop";
-const CONTRACT_VERSION: &str = "0.1.0";
+const CONTRACT_VERSION: &str = "0.2.0";
+
+const DEFAULT_CLAIMERS_LIMIT: u32 = 30;
+const MAX_CLAIMERS_LIMIT: u32 = 100;
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
 pub struct InstantiateMsg {
@@ -15,6 +18,17 @@ pub struct InstantiateMsg {
     pub denom: String,
     pub claim_amount: Uint128,
     pub total_amount: Uint128,
+    /// Unix timestamp (seconds) before which `Claim` is rejected. Open-ended if `None`.
+    pub start: Option<u64>,
+    /// Unix timestamp (seconds) after which `Claim` is rejected and `ReclaimUnclaimed`
+    /// becomes available to the admin. Open-ended if `None`.
+    pub deadline: Option<u64>,
+    /// Seconds over which a claim linearly vests. If `None`, `Claim` pays out
+    /// `claim_amount` immediately as before.
+    pub vesting_duration: Option<u64>,
+    /// Seconds after a claim during which nothing is released, even though
+    /// vesting has started accruing. Ignored unless `vesting_duration` is set.
+    pub cliff: Option<u64>,
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
@@ -32,6 +46,30 @@ pub enum ExecuteMsg {
         recipient: String,
         amount: Option<Coin>,
     },
+    /// Admin-only: after `deadline` has passed, sweep the allocation for any
+    /// claims that were never made back to the admin in a single transfer.
+    ReclaimUnclaimed {},
+    /// Admin-only killswitch: move the contract between `Normal`, `ClaimsPaused`
+    /// and `Frozen`.
+    SetStatus { status: ContractStatus },
+    /// Release the currently-accrued portion of a vesting grant. Only
+    /// meaningful when the config has `vesting_duration` set; callable by
+    /// anyone on behalf of `recipient` (defaults to the sender).
+    WithdrawVested { recipient: Option<String> },
+    /// Consume a single claim allocation split near-equally across `members`
+    /// (remainder goes to the first member). Only the sender is marked
+    /// claimed; the members are not, and can still `Claim` on their own.
+    ClaimSplit { members: Vec<String> },
+}
+
+/// Killswitch state. `ClaimsPaused` blocks `Claim` and `ClaimSplit` only; `Frozen` additionally
+/// blocks `UpdateConfig`, for use when the contract is under investigation.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ContractStatus {
+    Normal,
+    ClaimsPaused,
+    Frozen,
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
@@ -40,6 +78,11 @@ pub enum QueryMsg {
     Config {},
     IsClaimed { address: String },
     Stats {},
+    Claimers {
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    Vested { address: String },
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
@@ -49,11 +92,30 @@ pub struct Config {
     pub claim_amount: Uint128,
     pub total_amount: Uint128,
     pub max_claims: u64,
+    pub start: Option<u64>,
+    pub deadline: Option<u64>,
+    pub vesting_duration: Option<u64>,
+    pub cliff: Option<u64>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct ConfigResponse {
+    pub admin: Addr,
+    pub denom: String,
+    pub claim_amount: Uint128,
+    pub total_amount: Uint128,
+    pub max_claims: u64,
+    pub start: Option<u64>,
+    pub deadline: Option<u64>,
+    pub vesting_duration: Option<u64>,
+    pub cliff: Option<u64>,
+    pub status: ContractStatus,
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
 pub struct StatsResponse {
     pub claimed_count: u64,
+    pub status: ContractStatus,
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
@@ -61,6 +123,41 @@ pub struct IsClaimedResponse {
     pub is_claimed: bool,
 }
 
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct ClaimersResponse {
+    pub claimers: Vec<String>,
+}
+
+/// A claim recorded under vesting: `total` unlocks linearly from `granted_at`
+/// over the config's `vesting_duration`, minus whatever has already been
+/// paid out via `WithdrawVested`.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct VestingGrant {
+    pub total: Uint128,
+    pub released: Uint128,
+    pub granted_at: u64,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct VestedResponse {
+    pub claimable: Uint128,
+    pub locked: Uint128,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct MigrateMsg {}
+
+/// Shape of `Config` as stored by contract version `0.1.0`, before the claim
+/// window fields were added. Only used to read old state during `migrate`.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+struct ConfigV1 {
+    admin: Addr,
+    denom: String,
+    claim_amount: Uint128,
+    total_amount: Uint128,
+    max_claims: u64,
+}
+
 #[derive(thiserror::Error, Debug, PartialEq)]
 pub enum ContractError {
     #[error("{0}")]
@@ -86,11 +183,46 @@ pub enum ContractError {
 
     #[error("claim cap reached")]
     CapReached,
+
+    #[error("claim window has not started yet")]
+    ClaimNotStarted,
+
+    #[error("claim window has ended")]
+    ClaimEnded,
+
+    #[error("unclaimed tokens can only be reclaimed after the deadline has passed")]
+    ReclaimNotAllowed,
+
+    #[error("deadline must be after start")]
+    InvalidClaimWindow,
+
+    #[error("contract is paused")]
+    Paused,
+
+    #[error("contract is frozen")]
+    Frozen,
+
+    #[error("cannot migrate contract '{found}', expected '{expected}'")]
+    WrongContract { found: String, expected: String },
+
+    #[error("cannot migrate from version {found} to {target}")]
+    UnsupportedMigration { found: String, target: String },
+
+    #[error("no vesting grant found for this address")]
+    NoVestingGrant,
+
+    #[error("nothing has vested yet")]
+    NothingVested,
+
+    #[error("members must be a non-empty list of unique addresses, no larger than the claim amount")]
+    InvalidMembers,
 }
 
 const CONFIG: Item<Config> = Item::new("config");
 const CLAIMED: Map<&Addr, bool> = Map::new("claimed");
 const CLAIMED_COUNT: Item<u64> = Item::new("claimed_count");
+const STATUS: Item<ContractStatus> = Item::new("status");
+const VESTING_GRANTS: Map<&Addr, VestingGrant> = Map::new("vesting_grants");
 
 #[entry_point]
 pub fn instantiate(
@@ -118,16 +250,27 @@ pub fn instantiate(
 
     let max_claims = (msg.total_amount.u128() / msg.claim_amount.u128()) as u64;
 
+    if let (Some(start), Some(deadline)) = (msg.start, msg.deadline) {
+        if deadline <= start {
+            return Err(ContractError::InvalidClaimWindow);
+        }
+    }
+
     let cfg = Config {
         admin,
         denom: msg.denom,
         claim_amount: msg.claim_amount,
         total_amount: msg.total_amount,
         max_claims,
+        start: msg.start,
+        deadline: msg.deadline,
+        vesting_duration: msg.vesting_duration,
+        cliff: msg.cliff,
     };
 
     CONFIG.save(deps.storage, &cfg)?;
     CLAIMED_COUNT.save(deps.storage, &0u64)?;
+    STATUS.save(deps.storage, &ContractStatus::Normal)?;
 
     Ok(Response::new())
 }
@@ -140,7 +283,7 @@ pub fn execute(
     msg: ExecuteMsg,
 ) -> Result<Response, ContractError> {
     match msg {
-        ExecuteMsg::Claim { recipient } => execute_claim(deps, info, recipient),
+        ExecuteMsg::Claim { recipient } => execute_claim(deps, env, info, recipient),
         ExecuteMsg::UpdateConfig {
             admin,
             denom,
@@ -148,16 +291,39 @@ pub fn execute(
             total_amount,
         } => execute_update_config(deps, info, admin, denom, claim_amount, total_amount),
         ExecuteMsg::Withdraw { recipient, amount } => execute_withdraw(deps, env, info, recipient, amount),
+        ExecuteMsg::ReclaimUnclaimed {} => execute_reclaim_unclaimed(deps, env, info),
+        ExecuteMsg::SetStatus { status } => execute_set_status(deps, info, status),
+        ExecuteMsg::WithdrawVested { recipient } => execute_withdraw_vested(deps, env, info, recipient),
+        ExecuteMsg::ClaimSplit { members } => execute_claim_split(deps, env, info, members),
     }
 }
 
 fn execute_claim(
     deps: DepsMut,
+    env: Env,
     info: MessageInfo,
     recipient: Option<String>,
 ) -> Result<Response, ContractError> {
     let cfg = CONFIG.load(deps.storage)?;
 
+    match STATUS.load(deps.storage)? {
+        ContractStatus::Normal => {}
+        ContractStatus::ClaimsPaused => return Err(ContractError::Paused),
+        ContractStatus::Frozen => return Err(ContractError::Frozen),
+    }
+
+    let now = env.block.time.seconds();
+    if let Some(start) = cfg.start {
+        if now < start {
+            return Err(ContractError::ClaimNotStarted);
+        }
+    }
+    if let Some(deadline) = cfg.deadline {
+        if now > deadline {
+            return Err(ContractError::ClaimEnded);
+        }
+    }
+
     let recipient = match recipient {
         Some(r) => deps.api.addr_validate(&r)?,
         None => info.sender,
@@ -176,6 +342,19 @@ fn execute_claim(
     claimed_count = claimed_count.saturating_add(1);
     CLAIMED_COUNT.save(deps.storage, &claimed_count)?;
 
+    if cfg.vesting_duration.is_some() {
+        VESTING_GRANTS.save(
+            deps.storage,
+            &recipient,
+            &VestingGrant {
+                total: cfg.claim_amount,
+                released: Uint128::zero(),
+                granted_at: now,
+            },
+        )?;
+        return Ok(Response::new());
+    }
+
     let send = BankMsg::Send {
         to_address: recipient.to_string(),
         amount: vec![Coin {
@@ -187,6 +366,136 @@ fn execute_claim(
     Ok(Response::new().add_message(send))
 }
 
+fn execute_claim_split(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    members: Vec<String>,
+) -> Result<Response, ContractError> {
+    let cfg = CONFIG.load(deps.storage)?;
+
+    match STATUS.load(deps.storage)? {
+        ContractStatus::Normal => {}
+        ContractStatus::ClaimsPaused => return Err(ContractError::Paused),
+        ContractStatus::Frozen => return Err(ContractError::Frozen),
+    }
+
+    let now = env.block.time.seconds();
+    if let Some(start) = cfg.start {
+        if now < start {
+            return Err(ContractError::ClaimNotStarted);
+        }
+    }
+    if let Some(deadline) = cfg.deadline {
+        if now > deadline {
+            return Err(ContractError::ClaimEnded);
+        }
+    }
+
+    if members.is_empty() || members.len() as u128 > cfg.claim_amount.u128() {
+        return Err(ContractError::InvalidMembers);
+    }
+
+    if CLAIMED.may_load(deps.storage, &info.sender)?.unwrap_or(false) {
+        return Err(ContractError::AlreadyClaimed);
+    }
+
+    let mut recipients = Vec::with_capacity(members.len());
+    for m in members {
+        let addr = deps.api.addr_validate(&m)?;
+        if recipients.contains(&addr) {
+            return Err(ContractError::InvalidMembers);
+        }
+        recipients.push(addr);
+    }
+
+    let mut claimed_count = CLAIMED_COUNT.load(deps.storage)?;
+    if claimed_count >= cfg.max_claims {
+        return Err(ContractError::CapReached);
+    }
+
+    CLAIMED.save(deps.storage, &info.sender, &true)?;
+    claimed_count = claimed_count.saturating_add(1);
+    CLAIMED_COUNT.save(deps.storage, &claimed_count)?;
+
+    let share_count = recipients.len() as u128;
+    let base_share = cfg.claim_amount.u128() / share_count;
+    let remainder = cfg.claim_amount.u128() % share_count;
+
+    let messages = recipients.iter().enumerate().map(|(i, addr)| {
+        let amount = if i == 0 {
+            base_share + remainder
+        } else {
+            base_share
+        };
+        BankMsg::Send {
+            to_address: addr.to_string(),
+            amount: vec![Coin {
+                denom: cfg.denom.clone(),
+                amount: Uint128::new(amount),
+            }],
+        }
+    });
+
+    Ok(Response::new().add_messages(messages))
+}
+
+/// Amount of `grant.total` that has accrued as of `now`, ignoring anything
+/// already released. Nothing accrues before `cliff` has elapsed.
+fn accrued_amount(grant: &VestingGrant, duration: u64, cliff: Option<u64>, now: u64) -> Uint128 {
+    let elapsed = now.saturating_sub(grant.granted_at);
+    if let Some(cliff) = cliff {
+        if elapsed < cliff {
+            return Uint128::zero();
+        }
+    }
+    if duration == 0 || elapsed >= duration {
+        return grant.total;
+    }
+    grant
+        .total
+        .multiply_ratio(elapsed, duration)
+}
+
+fn execute_withdraw_vested(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    recipient: Option<String>,
+) -> Result<Response, ContractError> {
+    let cfg = CONFIG.load(deps.storage)?;
+    let duration = cfg.vesting_duration.unwrap_or(0);
+
+    let recipient = match recipient {
+        Some(r) => deps.api.addr_validate(&r)?,
+        None => info.sender,
+    };
+
+    let mut grant = VESTING_GRANTS
+        .may_load(deps.storage, &recipient)?
+        .ok_or(ContractError::NoVestingGrant)?;
+
+    let now = env.block.time.seconds();
+    let accrued = accrued_amount(&grant, duration, cfg.cliff, now);
+    let claimable = accrued.saturating_sub(grant.released);
+    if claimable.is_zero() {
+        return Err(ContractError::NothingVested);
+    }
+
+    grant.released += claimable;
+    VESTING_GRANTS.save(deps.storage, &recipient, &grant)?;
+
+    let send = BankMsg::Send {
+        to_address: recipient.to_string(),
+        amount: vec![Coin {
+            denom: cfg.denom,
+            amount: claimable,
+        }],
+    };
+
+    Ok(Response::new().add_message(send))
+}
+
 fn execute_update_config(
     deps: DepsMut,
     info: MessageInfo,
@@ -195,6 +504,10 @@ fn execute_update_config(
     claim_amount: Option<Uint128>,
     total_amount: Option<Uint128>,
 ) -> Result<Response, ContractError> {
+    if STATUS.load(deps.storage)? == ContractStatus::Frozen {
+        return Err(ContractError::Frozen);
+    }
+
     CONFIG.update(deps.storage, |mut cfg| {
         if info.sender != cfg.admin {
             return Err(ContractError::Unauthorized);
@@ -275,10 +588,81 @@ fn execute_withdraw(
     Ok(Response::new().add_message(msg))
 }
 
+fn execute_reclaim_unclaimed(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+) -> Result<Response, ContractError> {
+    let cfg = CONFIG.load(deps.storage)?;
+    if info.sender != cfg.admin {
+        return Err(ContractError::Unauthorized);
+    }
+
+    let deadline = cfg.deadline.ok_or(ContractError::ReclaimNotAllowed)?;
+    if env.block.time.seconds() <= deadline {
+        return Err(ContractError::ReclaimNotAllowed);
+    }
+
+    let claimed_count = CLAIMED_COUNT.load(deps.storage)?;
+    let unclaimed = cfg.max_claims.saturating_sub(claimed_count);
+    if unclaimed == 0 {
+        return Ok(Response::new());
+    }
+
+    let amount = cfg
+        .claim_amount
+        .checked_mul(Uint128::from(unclaimed))
+        .map_err(StdError::overflow)?;
+
+    // Mark every unclaimed slot as swept so a repeat call sees `unclaimed == 0`
+    // instead of resending the same amount.
+    CLAIMED_COUNT.save(deps.storage, &cfg.max_claims)?;
+
+    let msg = BankMsg::Send {
+        to_address: cfg.admin.to_string(),
+        amount: vec![Coin {
+            denom: cfg.denom,
+            amount,
+        }],
+    };
+
+    Ok(Response::new().add_message(msg))
+}
+
+fn execute_set_status(
+    deps: DepsMut,
+    info: MessageInfo,
+    status: ContractStatus,
+) -> Result<Response, ContractError> {
+    let cfg = CONFIG.load(deps.storage)?;
+    if info.sender != cfg.admin {
+        return Err(ContractError::Unauthorized);
+    }
+
+    STATUS.save(deps.storage, &status)?;
+
+    Ok(Response::new())
+}
+
 #[entry_point]
-pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
+pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
     match msg {
-        QueryMsg::Config {} => to_json_binary(&CONFIG.load(deps.storage)?),
+        QueryMsg::Config {} => {
+            let cfg = CONFIG.load(deps.storage)?;
+            let status = STATUS.load(deps.storage)?;
+            to_json_binary(&ConfigResponse {
+                admin: cfg.admin,
+                denom: cfg.denom,
+                claim_amount: cfg.claim_amount,
+                total_amount: cfg.total_amount,
+                max_claims: cfg.max_claims,
+                start: cfg.start,
+                deadline: cfg.deadline,
+                vesting_duration: cfg.vesting_duration,
+                cliff: cfg.cliff,
+                status,
+            })
+        }
         QueryMsg::IsClaimed { address } => {
             let addr = deps.api.addr_validate(&address)?;
             let is_claimed = CLAIMED.may_load(deps.storage, &addr)?.unwrap_or(false);
@@ -286,9 +670,91 @@ pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
         }
         QueryMsg::Stats {} => {
             let claimed_count = CLAIMED_COUNT.load(deps.storage)?;
-            to_json_binary(&StatsResponse { claimed_count })
+            let status = STATUS.load(deps.storage)?;
+            to_json_binary(&StatsResponse {
+                claimed_count,
+                status,
+            })
+        }
+        QueryMsg::Claimers { start_after, limit } => {
+            let limit = limit.unwrap_or(DEFAULT_CLAIMERS_LIMIT).min(MAX_CLAIMERS_LIMIT) as usize;
+            let start = start_after
+                .map(|s| deps.api.addr_validate(&s))
+                .transpose()?;
+            let start = start.as_ref().map(Bound::exclusive);
+
+            let claimers = CLAIMED
+                .keys(deps.storage, start, None, Order::Ascending)
+                .take(limit)
+                .map(|r| r.map(|addr| addr.to_string()))
+                .collect::<StdResult<Vec<_>>>()?;
+
+            to_json_binary(&ClaimersResponse { claimers })
+        }
+        QueryMsg::Vested { address } => {
+            let cfg = CONFIG.load(deps.storage)?;
+            let addr = deps.api.addr_validate(&address)?;
+            let grant = VESTING_GRANTS.may_load(deps.storage, &addr)?;
+
+            let (claimable, locked) = match grant {
+                Some(grant) => {
+                    let duration = cfg.vesting_duration.unwrap_or(0);
+                    let accrued = accrued_amount(&grant, duration, cfg.cliff, env.block.time.seconds());
+                    let claimable = accrued.saturating_sub(grant.released);
+                    let locked = grant.total - grant.released - claimable;
+                    (claimable, locked)
+                }
+                None => (Uint128::zero(), Uint128::zero()),
+            };
+
+            to_json_binary(&VestedResponse { claimable, locked })
+        }
+    }
+}
+
+#[entry_point]
+pub fn migrate(deps: DepsMut, _env: Env, _msg: MigrateMsg) -> Result<Response, ContractError> {
+    let stored = get_contract_version(deps.storage)?;
+    if stored.contract != CONTRACT_NAME {
+        return Err(ContractError::WrongContract {
+            found: stored.contract,
+            expected: CONTRACT_NAME.to_string(),
+        });
+    }
+
+    match stored.version.as_str() {
+        v if v == CONTRACT_VERSION => {}
+        "0.1.0" => {
+            const LEGACY_CONFIG: Item<ConfigV1> = Item::new("config");
+            let old = LEGACY_CONFIG.load(deps.storage)?;
+
+            CONFIG.save(
+                deps.storage,
+                &Config {
+                    admin: old.admin,
+                    denom: old.denom,
+                    claim_amount: old.claim_amount,
+                    total_amount: old.total_amount,
+                    max_claims: old.max_claims,
+                    start: None,
+                    deadline: None,
+                    vesting_duration: None,
+                    cliff: None,
+                },
+            )?;
+            STATUS.save(deps.storage, &ContractStatus::Normal)?;
+        }
+        other => {
+            return Err(ContractError::UnsupportedMigration {
+                found: other.to_string(),
+                target: CONTRACT_VERSION.to_string(),
+            })
         }
     }
+
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+
+    Ok(Response::new())
 }
 
 #[cfg(test)]
@@ -305,6 +771,10 @@ mod tests {
             denom: "uretro".to_string(),
             claim_amount: Uint128::new(2_500_000_000),
             total_amount: Uint128::new(5_000_000_000),
+            start: None,
+            deadline: None,
+            vesting_duration: None,
+            cliff: None,
         };
 
         instantiate(deps.as_mut(), mock_env(), mock_info("anyone", &[]), msg).unwrap();
@@ -328,4 +798,388 @@ mod tests {
         .unwrap_err();
         assert_eq!(err, ContractError::AlreadyClaimed);
     }
+
+    #[test]
+    fn claim_respects_window_and_reclaim_sweeps_remainder() {
+        let mut deps = mock_dependencies();
+        let mut env = mock_env();
+        env.block.time = cosmwasm_std::Timestamp::from_seconds(1_000);
+
+        let msg = InstantiateMsg {
+            admin: "cosmos1adminxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx".to_string(),
+            denom: "uretro".to_string(),
+            claim_amount: Uint128::new(2_500_000_000),
+            total_amount: Uint128::new(5_000_000_000),
+            start: Some(2_000),
+            deadline: Some(3_000),
+            vesting_duration: None,
+            cliff: None,
+        };
+        instantiate(deps.as_mut(), env.clone(), mock_info("anyone", &[]), msg).unwrap();
+
+        let err = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("cosmos1claimerxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx", &[]),
+            ExecuteMsg::Claim { recipient: None },
+        )
+        .unwrap_err();
+        assert_eq!(err, ContractError::ClaimNotStarted);
+
+        env.block.time = cosmwasm_std::Timestamp::from_seconds(4_000);
+        let err = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("cosmos1claimerxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx", &[]),
+            ExecuteMsg::Claim { recipient: None },
+        )
+        .unwrap_err();
+        assert_eq!(err, ContractError::ClaimEnded);
+
+        let res = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("cosmos1adminxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx", &[]),
+            ExecuteMsg::ReclaimUnclaimed {},
+        )
+        .unwrap();
+        assert_eq!(res.messages.len(), 1);
+
+        // A repeat sweep (e.g. a retried tx) must not resend the same amount.
+        let res = execute(
+            deps.as_mut(),
+            env,
+            mock_info("cosmos1adminxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx", &[]),
+            ExecuteMsg::ReclaimUnclaimed {},
+        )
+        .unwrap();
+        assert!(res.messages.is_empty());
+    }
+
+    #[test]
+    fn set_status_pauses_claims_and_freeze_blocks_config_updates() {
+        let mut deps = mock_dependencies();
+
+        let msg = InstantiateMsg {
+            admin: "cosmos1adminxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx".to_string(),
+            denom: "uretro".to_string(),
+            claim_amount: Uint128::new(2_500_000_000),
+            total_amount: Uint128::new(5_000_000_000),
+            start: None,
+            deadline: None,
+            vesting_duration: None,
+            cliff: None,
+        };
+        instantiate(deps.as_mut(), mock_env(), mock_info("anyone", &[]), msg).unwrap();
+
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("cosmos1adminxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx", &[]),
+            ExecuteMsg::SetStatus {
+                status: ContractStatus::ClaimsPaused,
+            },
+        )
+        .unwrap();
+
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("cosmos1claimerxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx", &[]),
+            ExecuteMsg::Claim { recipient: None },
+        )
+        .unwrap_err();
+        assert_eq!(err, ContractError::Paused);
+
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("cosmos1adminxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx", &[]),
+            ExecuteMsg::SetStatus {
+                status: ContractStatus::Frozen,
+            },
+        )
+        .unwrap();
+
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("cosmos1adminxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx", &[]),
+            ExecuteMsg::UpdateConfig {
+                admin: None,
+                denom: None,
+                claim_amount: None,
+                total_amount: None,
+            },
+        )
+        .unwrap_err();
+        assert_eq!(err, ContractError::Frozen);
+    }
+
+    #[test]
+    fn migrate_backfills_claim_window_from_v1() {
+        let mut deps = mock_dependencies();
+
+        cw2::set_contract_version(deps.as_mut().storage, CONTRACT_NAME, "0.1.0").unwrap();
+        const LEGACY_CONFIG: Item<ConfigV1> = Item::new("config");
+        LEGACY_CONFIG
+            .save(
+                deps.as_mut().storage,
+                &ConfigV1 {
+                    admin: Addr::unchecked("cosmos1adminxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx"),
+                    denom: "uretro".to_string(),
+                    claim_amount: Uint128::new(2_500_000_000),
+                    total_amount: Uint128::new(5_000_000_000),
+                    max_claims: 2,
+                },
+            )
+            .unwrap();
+        CLAIMED_COUNT.save(deps.as_mut().storage, &0u64).unwrap();
+
+        migrate(deps.as_mut(), mock_env(), MigrateMsg {}).unwrap();
+
+        let cfg = CONFIG.load(deps.as_ref().storage).unwrap();
+        assert_eq!(cfg.start, None);
+        assert_eq!(cfg.deadline, None);
+        assert_eq!(STATUS.load(deps.as_ref().storage).unwrap(), ContractStatus::Normal);
+        assert_eq!(get_contract_version(deps.as_ref().storage).unwrap().version, CONTRACT_VERSION);
+    }
+
+    #[test]
+    fn claimers_query_is_paginated_and_sorted() {
+        let mut deps = mock_dependencies();
+
+        let msg = InstantiateMsg {
+            admin: "cosmos1adminxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx".to_string(),
+            denom: "uretro".to_string(),
+            claim_amount: Uint128::new(1_000_000),
+            total_amount: Uint128::new(3_000_000),
+            start: None,
+            deadline: None,
+            vesting_duration: None,
+            cliff: None,
+        };
+        instantiate(deps.as_mut(), mock_env(), mock_info("anyone", &[]), msg).unwrap();
+
+        for claimer in ["alice", "bob", "carol"] {
+            execute(
+                deps.as_mut(),
+                mock_env(),
+                mock_info(claimer, &[]),
+                ExecuteMsg::Claim { recipient: None },
+            )
+            .unwrap();
+        }
+
+        let res = query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::Claimers {
+                start_after: None,
+                limit: Some(2),
+            },
+        )
+        .unwrap();
+        let page: ClaimersResponse = cosmwasm_std::from_json(res).unwrap();
+        assert_eq!(page.claimers, vec!["alice".to_string(), "bob".to_string()]);
+
+        let res = query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::Claimers {
+                start_after: Some("bob".to_string()),
+                limit: None,
+            },
+        )
+        .unwrap();
+        let page: ClaimersResponse = cosmwasm_std::from_json(res).unwrap();
+        assert_eq!(page.claimers, vec!["carol".to_string()]);
+    }
+
+    #[test]
+    fn claim_vests_linearly_with_cliff() {
+        let mut deps = mock_dependencies();
+        let mut env = mock_env();
+        env.block.time = cosmwasm_std::Timestamp::from_seconds(0);
+
+        let msg = InstantiateMsg {
+            admin: "cosmos1adminxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx".to_string(),
+            denom: "uretro".to_string(),
+            claim_amount: Uint128::new(1_000),
+            total_amount: Uint128::new(1_000),
+            start: None,
+            deadline: None,
+            vesting_duration: Some(1_000),
+            cliff: Some(100),
+        };
+        instantiate(deps.as_mut(), env.clone(), mock_info("anyone", &[]), msg).unwrap();
+
+        let res = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("cosmos1claimerxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx", &[]),
+            ExecuteMsg::Claim { recipient: None },
+        )
+        .unwrap();
+        assert!(res.messages.is_empty());
+
+        env.block.time = cosmwasm_std::Timestamp::from_seconds(50);
+        let err = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("cosmos1claimerxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx", &[]),
+            ExecuteMsg::WithdrawVested { recipient: None },
+        )
+        .unwrap_err();
+        assert_eq!(err, ContractError::NothingVested);
+
+        env.block.time = cosmwasm_std::Timestamp::from_seconds(500);
+        let res = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("cosmos1claimerxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx", &[]),
+            ExecuteMsg::WithdrawVested { recipient: None },
+        )
+        .unwrap();
+        assert_eq!(res.messages.len(), 1);
+
+        let vested: VestedResponse = cosmwasm_std::from_json(
+            query(
+                deps.as_ref(),
+                env,
+                QueryMsg::Vested {
+                    address: "cosmos1claimerxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx".to_string(),
+                },
+            )
+            .unwrap(),
+        )
+        .unwrap();
+        assert_eq!(vested.claimable, Uint128::zero());
+        assert_eq!(vested.locked, Uint128::new(500));
+    }
+
+    #[test]
+    fn claim_split_divides_allocation_without_dust_and_consumes_one_slot() {
+        let mut deps = mock_dependencies();
+
+        let msg = InstantiateMsg {
+            admin: "cosmos1adminxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx".to_string(),
+            denom: "uretro".to_string(),
+            claim_amount: Uint128::new(100),
+            total_amount: Uint128::new(200),
+            start: None,
+            deadline: None,
+            vesting_duration: None,
+            cliff: None,
+        };
+        instantiate(deps.as_mut(), mock_env(), mock_info("anyone", &[]), msg).unwrap();
+
+        let res = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("anyone", &[]),
+            ExecuteMsg::ClaimSplit {
+                members: vec!["alice".to_string(), "bob".to_string(), "carol".to_string()],
+            },
+        )
+        .unwrap();
+
+        let total: u128 = res
+            .messages
+            .iter()
+            .map(|m| match &m.msg {
+                cosmwasm_std::CosmosMsg::Bank(BankMsg::Send { amount, .. }) => amount[0].amount.u128(),
+                _ => 0,
+            })
+            .sum();
+        assert_eq!(total, 100);
+        assert_eq!(res.messages.len(), 3);
+
+        let stats: StatsResponse =
+            cosmwasm_std::from_json(query(deps.as_ref(), mock_env(), QueryMsg::Stats {}).unwrap()).unwrap();
+        assert_eq!(stats.claimed_count, 1);
+
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("anyone", &[]),
+            ExecuteMsg::ClaimSplit {
+                members: vec!["alice".to_string(), "dave".to_string()],
+            },
+        )
+        .unwrap_err();
+        assert_eq!(err, ContractError::AlreadyClaimed);
+
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("someone_else", &[]),
+            ExecuteMsg::ClaimSplit {
+                members: vec!["eve".to_string(), "eve".to_string()],
+            },
+        )
+        .unwrap_err();
+        assert_eq!(err, ContractError::InvalidMembers);
+    }
+
+    #[test]
+    fn claim_split_does_not_gate_or_mark_member_addresses() {
+        let mut deps = mock_dependencies();
+
+        let msg = InstantiateMsg {
+            admin: "cosmos1adminxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx".to_string(),
+            denom: "uretro".to_string(),
+            claim_amount: Uint128::new(100),
+            total_amount: Uint128::new(300),
+            start: None,
+            deadline: None,
+            vesting_duration: None,
+            cliff: None,
+        };
+        instantiate(deps.as_mut(), mock_env(), mock_info("anyone", &[]), msg).unwrap();
+
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("payer_one", &[]),
+            ExecuteMsg::ClaimSplit {
+                members: vec!["alice".to_string(), "bob".to_string()],
+            },
+        )
+        .unwrap();
+
+        // Naming "alice" as a split member must not lock her out of her own
+        // normal Claim, nor stop a different sender from naming her as a member.
+        let res = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("alice", &[]),
+            ExecuteMsg::Claim { recipient: None },
+        )
+        .unwrap();
+        assert_eq!(res.messages.len(), 1);
+
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("payer_two", &[]),
+            ExecuteMsg::ClaimSplit {
+                members: vec!["alice".to_string(), "carol".to_string()],
+            },
+        )
+        .unwrap();
+
+        // A second split from the same sender, even with a fresh member set,
+        // must fail on the sender's own claim status, not on the members.
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("payer_two", &[]),
+            ExecuteMsg::ClaimSplit {
+                members: vec!["dave".to_string()],
+            },
+        )
+        .unwrap_err();
+        assert_eq!(err, ContractError::AlreadyClaimed);
+    }
 }
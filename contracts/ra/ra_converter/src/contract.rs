@@ -1,12 +1,20 @@
 use cosmwasm_std::{
-    to_json_binary, BankMsg, Binary, Deps, DepsMut, Env, MessageInfo, Response, StdResult, WasmMsg,
+    to_json_binary, BankMsg, Binary, Coin, Deps, DepsMut, Env, MessageInfo, Order, Response,
+    StdResult, Uint128, Uint256, WasmMsg,
 };
 use cw2::set_contract_version;
-use cw20::Cw20ExecuteMsg;
+use cw20::{Cw20ExecuteMsg, Cw20ReceiveMsg};
 
 use crate::error::ContractError;
-use crate::msg::{ConfigResponse, ExecuteMsg, InstantiateMsg, QueryMsg};
-use crate::state::{Config, CONFIG};
+use crate::msg::{
+    ConfigResponse, ExecuteMsg, InstantiateMsg, ProgressResponse, ProxyExecuteMsg, QueryMsg,
+    RouteInit, RouteResponse, RoutesResponse,
+};
+use crate::state::{
+    Config, PendingJob, TokenRoute, BADGES, CONFIG, CUMULATIVE, JOB_SEQ, PENDING_JOBS, ROUTES,
+};
+
+type Cw721ExecuteMsg = cw721_base::msg::ExecuteMsg<cw721_base::Extension, cosmwasm_std::Empty>;
 
 const CONTRACT_NAME: &str = "ra_converter";
 const CONTRACT_VERSION: &str = "0.1.0";
@@ -19,33 +27,41 @@ pub fn instantiate(
 ) -> Result<Response, ContractError> {
     set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
 
-    let ra_cw20_addr = deps.api.addr_validate(&msg.ra_cw20_addr)?;
-    let fee_collector_addr = deps.api.addr_validate(&msg.fee_collector_addr)?;
-
     let operator = match msg.operator {
         Some(op) => Some(deps.api.addr_validate(&op)?),
         None => Some(info.sender.clone()),
     };
+    let nois_proxy_addr = msg
+        .nois_proxy_addr
+        .map(|a| deps.api.addr_validate(&a))
+        .transpose()?;
+    let nft_contract_addr = msg
+        .nft_contract_addr
+        .map(|a| deps.api.addr_validate(&a))
+        .transpose()?;
 
-    let cfg = Config {
-        ra_cw20_addr,
-        native_denom: msg.native_denom,
-        fee_collector_addr,
-        operator,
-    };
+    CONFIG.save(
+        deps.storage,
+        &Config {
+            operator,
+            nois_proxy_addr,
+            max_bonus_bps: msg.max_bonus_bps,
+            nft_contract_addr,
+            milestones: msg.milestones,
+        },
+    )?;
+    JOB_SEQ.save(deps.storage, &0u64)?;
 
-    CONFIG.save(deps.storage, &cfg)?;
+    for route in msg.routes {
+        register_route(deps.storage, deps.api, route)?;
+    }
 
-    Ok(Response::new()
-        .add_attribute("action", "instantiate")
-        .add_attribute("native_denom", cfg.native_denom)
-        .add_attribute("ra_cw20_addr", cfg.ra_cw20_addr.to_string())
-        .add_attribute("fee_collector_addr", cfg.fee_collector_addr.to_string()))
+    Ok(Response::new().add_attribute("action", "instantiate"))
 }
 
 pub fn execute(
     deps: DepsMut,
-    _env: Env,
+    env: Env,
     info: MessageInfo,
     msg: ExecuteMsg,
 ) -> Result<Response, ContractError> {
@@ -53,23 +69,177 @@ pub fn execute(
         ExecuteMsg::Convert {} => execute_convert(deps, info, None),
         ExecuteMsg::RewardMint { recipient } => execute_reward_mint(deps, info, recipient),
         ExecuteMsg::UpdateOperator { operator } => execute_update_operator(deps, info, operator),
+        ExecuteMsg::RegisterDenom(route) => execute_register_denom(deps, info, route),
+        ExecuteMsg::UpdateDenom(route) => execute_update_denom(deps, info, route),
+        ExecuteMsg::Receive(receive_msg) => execute_receive(deps, info, receive_msg),
+        ExecuteMsg::JackpotConvert {} => execute_jackpot_convert(deps, env, info),
+        ExecuteMsg::NoisReceive { job_id, randomness } => {
+            execute_nois_receive(deps, info, job_id, randomness)
+        }
     }
 }
 
-fn extract_amount(info: &MessageInfo, denom: &str) -> Result<u128, ContractError> {
-    let mut amt: u128 = 0;
-    for c in &info.funds {
-        if c.denom == denom {
-            amt = amt.saturating_add(c.amount.u128());
-        } else if c.amount.u128() > 0 {
-            // any other denom attached is rejected
-            return Err(ContractError::UnsupportedDenom {});
+fn require_operator(cfg: &Config, sender: &cosmwasm_std::Addr) -> Result<(), ContractError> {
+    let operator = cfg.operator.clone().ok_or(ContractError::Unauthorized {})?;
+    if *sender != operator {
+        return Err(ContractError::Unauthorized {});
+    }
+    Ok(())
+}
+
+fn register_route(
+    storage: &mut dyn cosmwasm_std::Storage,
+    api: &dyn cosmwasm_std::Api,
+    route: RouteInit,
+) -> Result<(), ContractError> {
+    if ROUTES.has(storage, route.denom.clone()) {
+        return Err(ContractError::DenomAlreadyRegistered {});
+    }
+    if route.rate_num.is_zero() || route.rate_den.is_zero() {
+        return Err(ContractError::InvalidRate {});
+    }
+    if route.reserve_bps > 10_000 {
+        return Err(ContractError::InvalidReserveBps {});
+    }
+
+    let ra_cw20_addr = api.addr_validate(&route.ra_cw20_addr)?;
+    if cw20_addr_in_use(storage, &ra_cw20_addr, None)? {
+        return Err(ContractError::Cw20AddrAlreadyRegistered {});
+    }
+
+    let token_route = TokenRoute {
+        ra_cw20_addr,
+        fee_collector_addr: api.addr_validate(&route.fee_collector_addr)?,
+        rate_num: route.rate_num,
+        rate_den: route.rate_den,
+        reserve_bps: route.reserve_bps,
+        reserve_balance: Uint128::zero(),
+    };
+    ROUTES.save(storage, route.denom, &token_route)?;
+    Ok(())
+}
+
+/// True if `ra_cw20_addr` already belongs to a route other than `exclude_denom`.
+///
+/// Routes are looked up by the sender of the `Receive` hook, so two denoms
+/// sharing one RA cw20 would make that lookup ambiguous.
+fn cw20_addr_in_use(
+    storage: &dyn cosmwasm_std::Storage,
+    ra_cw20_addr: &cosmwasm_std::Addr,
+    exclude_denom: Option<&str>,
+) -> StdResult<bool> {
+    for item in ROUTES.range(storage, None, None, Order::Ascending) {
+        let (denom, route) = item?;
+        if Some(denom.as_str()) == exclude_denom {
+            continue;
         }
+        if route.ra_cw20_addr == *ra_cw20_addr {
+            return Ok(true);
+        }
+    }
+    Ok(false)
+}
+
+fn execute_register_denom(
+    deps: DepsMut,
+    info: MessageInfo,
+    route: RouteInit,
+) -> Result<Response, ContractError> {
+    let cfg = CONFIG.load(deps.storage)?;
+    require_operator(&cfg, &info.sender)?;
+
+    let denom = route.denom.clone();
+    register_route(deps.storage, deps.api, route)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "register_denom")
+        .add_attribute("denom", denom))
+}
+
+fn execute_update_denom(
+    deps: DepsMut,
+    info: MessageInfo,
+    route: RouteInit,
+) -> Result<Response, ContractError> {
+    let cfg = CONFIG.load(deps.storage)?;
+    require_operator(&cfg, &info.sender)?;
+
+    if route.rate_num.is_zero() || route.rate_den.is_zero() {
+        return Err(ContractError::InvalidRate {});
     }
-    if amt == 0 {
-        return Err(ContractError::InvalidFunds {});
+    if route.reserve_bps > 10_000 {
+        return Err(ContractError::InvalidReserveBps {});
+    }
+
+    let existing = ROUTES
+        .may_load(deps.storage, route.denom.clone())?
+        .ok_or(ContractError::UnregisteredDenom {})?;
+
+    let ra_cw20_addr = deps.api.addr_validate(&route.ra_cw20_addr)?;
+    if cw20_addr_in_use(deps.storage, &ra_cw20_addr, Some(&route.denom))? {
+        return Err(ContractError::Cw20AddrAlreadyRegistered {});
     }
-    Ok(amt)
+
+    let updated = TokenRoute {
+        ra_cw20_addr,
+        fee_collector_addr: deps.api.addr_validate(&route.fee_collector_addr)?,
+        rate_num: route.rate_num,
+        rate_den: route.rate_den,
+        reserve_bps: route.reserve_bps,
+        reserve_balance: existing.reserve_balance,
+    };
+    ROUTES.save(deps.storage, route.denom.clone(), &updated)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "update_denom")
+        .add_attribute("denom", route.denom))
+}
+
+/// Converts a native amount into RA using `rate_num / rate_den`, computing the
+/// intermediate product in 256 bits so large deposits can't overflow `u128`.
+fn convert_amount(amt: u128, rate_num: Uint128, rate_den: Uint128) -> Result<u128, ContractError> {
+    let minted_256: Uint256 = Uint128::new(amt).full_mul(rate_num) / Uint256::from(rate_den);
+    let minted: Uint128 =
+        Uint128::try_from(minted_256).map_err(|_| ContractError::MintOverflow {})?;
+    Ok(minted.u128())
+}
+
+/// Inverse of [`convert_amount`]: how much native is owed for redeeming `ra_amt` RA.
+fn redeem_amount(ra_amt: u128, rate_num: Uint128, rate_den: Uint128) -> Result<u128, ContractError> {
+    let native_256: Uint256 = Uint128::new(ra_amt).full_mul(rate_den) / Uint256::from(rate_num);
+    let native: Uint128 =
+        Uint128::try_from(native_256).map_err(|_| ContractError::MintOverflow {})?;
+    Ok(native.u128())
+}
+
+/// Applies basis points (0-10000) to `amt`, computed in 256 bits to avoid overflow.
+fn apply_bps(amt: u128, bps: u64) -> Result<u128, ContractError> {
+    if bps == 0 {
+        return Ok(0);
+    }
+    let result_256: Uint256 = Uint128::new(amt).full_mul(Uint128::new(bps as u128))
+        / Uint256::from(10_000u128);
+    let result: Uint128 =
+        Uint128::try_from(result_256).map_err(|_| ContractError::MintOverflow {})?;
+    Ok(result.u128())
+}
+
+/// Finds the single nonzero denom attached to the message and its amount.
+fn extract_funds(info: &MessageInfo) -> Result<(String, u128), ContractError> {
+    let mut found: Option<(String, u128)> = None;
+    for c in &info.funds {
+        if c.amount.is_zero() {
+            continue;
+        }
+        match found {
+            Some((ref d, amt)) if *d == c.denom => {
+                found = Some((c.denom.clone(), amt.saturating_add(c.amount.u128())));
+            }
+            Some(_) => return Err(ContractError::UnsupportedDenom {}),
+            None => found = Some((c.denom.clone(), c.amount.u128())),
+        }
+    }
+    found.ok_or(ContractError::InvalidFunds {})
 }
 
 fn execute_convert(
@@ -77,37 +247,112 @@ fn execute_convert(
     info: MessageInfo,
     recipient_override: Option<String>,
 ) -> Result<Response, ContractError> {
-    let cfg = CONFIG.load(deps.storage)?;
-    let amt = extract_amount(&info, &cfg.native_denom)?;
+    let (denom, amt) = extract_funds(&info)?;
+    let route = ROUTES
+        .may_load(deps.storage, denom.clone())?
+        .ok_or(ContractError::UnregisteredDenom {})?;
 
     let recipient = match recipient_override {
         Some(r) => deps.api.addr_validate(&r)?,
         None => info.sender.clone(),
     };
 
-    // Send all incoming native to fee collector.
-    let send_native = BankMsg::Send {
-        to_address: cfg.fee_collector_addr.to_string(),
-        amount: info.funds.clone(),
-    };
+    let minted = convert_amount(amt, route.rate_num, route.rate_den)?;
+    if minted == 0 {
+        return Err(ContractError::DustAmount {});
+    }
+
+    // Keep a configured fraction of the incoming native as a redemption reserve;
+    // the rest is forwarded to the route's fee collector.
+    let reserve_amt = apply_bps(amt, route.reserve_bps)?;
+    let forwarded_amt = amt - reserve_amt;
+
+    ROUTES.update(deps.storage, denom.clone(), |r| -> StdResult<_> {
+        let mut r = r.expect("route exists, just loaded above");
+        r.reserve_balance = r.reserve_balance.checked_add(Uint128::new(reserve_amt))?;
+        Ok(r)
+    })?;
 
-    // Mint RA 1:1 to the recipient.
     let mint = WasmMsg::Execute {
-        contract_addr: cfg.ra_cw20_addr.to_string(),
+        contract_addr: route.ra_cw20_addr.to_string(),
         msg: to_json_binary(&Cw20ExecuteMsg::Mint {
             recipient: recipient.to_string(),
-            amount: amt.into(),
+            amount: Uint128::new(minted),
         })?,
         funds: vec![],
     };
 
-    Ok(Response::new()
-        .add_message(send_native)
+    let mut resp = Response::new();
+    if forwarded_amt > 0 {
+        resp = resp.add_message(BankMsg::Send {
+            to_address: route.fee_collector_addr.to_string(),
+            amount: vec![Coin {
+                denom: denom.clone(),
+                amount: Uint128::new(forwarded_amt),
+            }],
+        });
+    }
+
+    let badge_msgs = award_milestone_badges(deps.storage, &recipient, Uint128::new(minted))?;
+
+    Ok(resp
         .add_message(mint)
+        .add_messages(badge_msgs)
         .add_attribute("action", "convert")
         .add_attribute("recipient", recipient.to_string())
         .add_attribute("amount", amt.to_string())
-        .add_attribute("denom", cfg.native_denom))
+        .add_attribute("minted", minted.to_string())
+        .add_attribute("reserved", reserve_amt.to_string())
+        .add_attribute("denom", denom))
+}
+
+/// Adds `amt` (minted RA, not raw native) to `recipient`'s cumulative total
+/// and mints one badge per configured milestone the new total crosses for
+/// the first time. Using minted RA keeps the threshold meaningful across
+/// routes with different rates, since raw native units aren't comparable
+/// between denoms.
+fn award_milestone_badges(
+    storage: &mut dyn cosmwasm_std::Storage,
+    recipient: &cosmwasm_std::Addr,
+    amt: Uint128,
+) -> Result<Vec<WasmMsg>, ContractError> {
+    let cfg = CONFIG.load(storage)?;
+    let Some(nft_contract_addr) = cfg.nft_contract_addr else {
+        // No badge program configured; still track cumulative for when one is.
+        CUMULATIVE.update(storage, recipient, |c| -> StdResult<_> {
+            Ok(c.unwrap_or_default().checked_add(amt)?)
+        })?;
+        return Ok(vec![]);
+    };
+
+    let updated = CUMULATIVE.update(storage, recipient, |c| -> StdResult<_> {
+        Ok(c.unwrap_or_default().checked_add(amt)?)
+    })?;
+
+    let mut msgs = vec![];
+    for (idx, threshold) in cfg.milestones.iter().enumerate() {
+        let idx = idx as u64;
+        if updated < *threshold {
+            continue;
+        }
+        if BADGES.has(storage, (recipient, idx)) {
+            continue;
+        }
+        BADGES.save(storage, (recipient, idx), &true)?;
+
+        msgs.push(WasmMsg::Execute {
+            contract_addr: nft_contract_addr.to_string(),
+            msg: to_json_binary(&Cw721ExecuteMsg::Mint {
+                token_id: format!("{recipient}-milestone-{idx}"),
+                owner: recipient.to_string(),
+                token_uri: None,
+                extension: None,
+            })?,
+            funds: vec![],
+        });
+    }
+
+    Ok(msgs)
 }
 
 fn execute_reward_mint(
@@ -116,10 +361,7 @@ fn execute_reward_mint(
     recipient: String,
 ) -> Result<Response, ContractError> {
     let cfg = CONFIG.load(deps.storage)?;
-    let operator = cfg.operator.clone().ok_or(ContractError::Unauthorized {})?;
-    if info.sender != operator {
-        return Err(ContractError::Unauthorized {});
-    }
+    require_operator(&cfg, &info.sender)?;
 
     execute_convert(deps, info, Some(recipient))
 }
@@ -130,10 +372,7 @@ fn execute_update_operator(
     operator: Option<String>,
 ) -> Result<Response, ContractError> {
     CONFIG.update(deps.storage, |mut cfg| -> Result<_, ContractError> {
-        let current = cfg.operator.clone().ok_or(ContractError::Unauthorized {})?;
-        if info.sender != current {
-            return Err(ContractError::Unauthorized {});
-        }
+        require_operator(&cfg, &info.sender)?;
         cfg.operator = match operator {
             Some(op) => Some(deps.api.addr_validate(&op)?),
             None => None,
@@ -144,18 +383,233 @@ fn execute_update_operator(
     Ok(Response::new().add_attribute("action", "update_operator"))
 }
 
+fn execute_receive(
+    deps: DepsMut,
+    info: MessageInfo,
+    receive_msg: Cw20ReceiveMsg,
+) -> Result<Response, ContractError> {
+    let (denom, route) = ROUTES
+        .range(deps.storage, None, None, Order::Ascending)
+        .find_map(|item| {
+            let (denom, route) = item.ok()?;
+            (route.ra_cw20_addr == info.sender).then_some((denom, route))
+        })
+        .ok_or(ContractError::Unauthorized {})?;
+
+    let native_owed = redeem_amount(receive_msg.amount.u128(), route.rate_num, route.rate_den)?;
+
+    if route.reserve_balance.u128() < native_owed {
+        return Err(ContractError::InsufficientReserve {});
+    }
+    ROUTES.update(deps.storage, denom.clone(), |r| -> StdResult<_> {
+        let mut r = r.expect("route exists, just loaded above");
+        r.reserve_balance = Uint128::new(r.reserve_balance.u128() - native_owed);
+        Ok(r)
+    })?;
+
+    let burn = WasmMsg::Execute {
+        contract_addr: route.ra_cw20_addr.to_string(),
+        msg: to_json_binary(&Cw20ExecuteMsg::Burn {
+            amount: receive_msg.amount,
+        })?,
+        funds: vec![],
+    };
+
+    let send_native = BankMsg::Send {
+        to_address: receive_msg.sender.clone(),
+        amount: vec![Coin {
+            denom,
+            amount: Uint128::new(native_owed),
+        }],
+    };
+
+    Ok(Response::new()
+        .add_message(burn)
+        .add_message(send_native)
+        .add_attribute("action", "redeem")
+        .add_attribute("sender", receive_msg.sender)
+        .add_attribute("ra_burned", receive_msg.amount.to_string())
+        .add_attribute("native_paid", native_owed.to_string()))
+}
+
+fn execute_jackpot_convert(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+) -> Result<Response, ContractError> {
+    let cfg = CONFIG.load(deps.storage)?;
+    let nois_proxy_addr = cfg
+        .nois_proxy_addr
+        .clone()
+        .ok_or(ContractError::NoisProxyNotConfigured {})?;
+
+    let (denom, amt) = extract_funds(&info)?;
+    let route = ROUTES
+        .may_load(deps.storage, denom.clone())?
+        .ok_or(ContractError::UnregisteredDenom {})?;
+
+    let base_minted = convert_amount(amt, route.rate_num, route.rate_den)?;
+    if base_minted == 0 {
+        return Err(ContractError::DustAmount {});
+    }
+
+    let job_seq = JOB_SEQ.update(deps.storage, |n| -> StdResult<_> { Ok(n + 1) })?;
+    let job_id = format!("jackpot-{}-{}", env.block.height, job_seq);
+    if PENDING_JOBS.has(deps.storage, job_id.clone()) {
+        return Err(ContractError::DuplicateJobId {});
+    }
+    PENDING_JOBS.save(
+        deps.storage,
+        job_id.clone(),
+        &PendingJob {
+            recipient: info.sender.clone(),
+            ra_cw20_addr: route.ra_cw20_addr.clone(),
+            denom: denom.clone(),
+            base_amount: Uint128::new(base_minted),
+        },
+    )?;
+
+    let reserve_amt = apply_bps(amt, route.reserve_bps)?;
+    let forwarded_amt = amt - reserve_amt;
+    ROUTES.update(deps.storage, denom.clone(), |r| -> StdResult<_> {
+        let mut r = r.expect("route exists, just loaded above");
+        r.reserve_balance = r.reserve_balance.checked_add(Uint128::new(reserve_amt))?;
+        Ok(r)
+    })?;
+
+    let mut resp = Response::new();
+    if forwarded_amt > 0 {
+        resp = resp.add_message(BankMsg::Send {
+            to_address: route.fee_collector_addr.to_string(),
+            amount: vec![Coin {
+                denom,
+                amount: Uint128::new(forwarded_amt),
+            }],
+        });
+    }
+
+    let request_randomness = WasmMsg::Execute {
+        contract_addr: nois_proxy_addr.to_string(),
+        msg: to_json_binary(&ProxyExecuteMsg::GetNextRandomness {
+            job_id: job_id.clone(),
+        })?,
+        funds: vec![],
+    };
+
+    Ok(resp
+        .add_message(request_randomness)
+        .add_attribute("action", "jackpot_convert")
+        .add_attribute("job_id", job_id)
+        .add_attribute("base_amount", base_minted.to_string()))
+}
+
+fn execute_nois_receive(
+    deps: DepsMut,
+    info: MessageInfo,
+    job_id: String,
+    randomness: Binary,
+) -> Result<Response, ContractError> {
+    let cfg = CONFIG.load(deps.storage)?;
+    let nois_proxy_addr = cfg.nois_proxy_addr.ok_or(ContractError::Unauthorized {})?;
+    if info.sender != nois_proxy_addr {
+        return Err(ContractError::Unauthorized {});
+    }
+    if randomness.len() != 32 {
+        return Err(ContractError::InvalidRandomness {});
+    }
+
+    let job = PENDING_JOBS
+        .may_load(deps.storage, job_id.clone())?
+        .ok_or(ContractError::UnknownJobId {})?;
+    PENDING_JOBS.remove(deps.storage, job_id.clone());
+
+    let mut first8 = [0u8; 8];
+    first8.copy_from_slice(&randomness.as_slice()[0..8]);
+    let draw = u64::from_le_bytes(first8);
+
+    let bonus_bps = if cfg.max_bonus_bps == 0 {
+        0
+    } else {
+        draw % cfg.max_bonus_bps
+    };
+    let multiplier_bps = 10_000u128 + bonus_bps as u128;
+    let boosted_256: Uint256 =
+        job.base_amount.full_mul(Uint128::new(multiplier_bps)) / Uint256::from(10_000u128);
+    let boosted: Uint128 =
+        Uint128::try_from(boosted_256).map_err(|_| ContractError::MintOverflow {})?;
+
+    let mint = WasmMsg::Execute {
+        contract_addr: job.ra_cw20_addr.to_string(),
+        msg: to_json_binary(&Cw20ExecuteMsg::Mint {
+            recipient: job.recipient.to_string(),
+            amount: boosted,
+        })?,
+        funds: vec![],
+    };
+
+    Ok(Response::new()
+        .add_message(mint)
+        .add_attribute("action", "nois_receive")
+        .add_attribute("job_id", job_id)
+        .add_attribute("recipient", job.recipient.to_string())
+        .add_attribute("minted", boosted.to_string()))
+}
+
 pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
     match msg {
         QueryMsg::Config {} => to_json_binary(&query_config(deps)?),
+        QueryMsg::Routes {} => to_json_binary(&query_routes(deps)?),
+        QueryMsg::Route { denom } => to_json_binary(&query_route(deps, denom)?),
+        QueryMsg::Progress { address } => to_json_binary(&query_progress(deps, address)?),
     }
 }
 
 fn query_config(deps: Deps) -> StdResult<ConfigResponse> {
     let cfg = CONFIG.load(deps.storage)?;
     Ok(ConfigResponse {
-        ra_cw20_addr: cfg.ra_cw20_addr.to_string(),
-        native_denom: cfg.native_denom,
-        fee_collector_addr: cfg.fee_collector_addr.to_string(),
         operator: cfg.operator.map(|a| a.to_string()),
+        nois_proxy_addr: cfg.nois_proxy_addr.map(|a| a.to_string()),
+        max_bonus_bps: cfg.max_bonus_bps,
+        nft_contract_addr: cfg.nft_contract_addr.map(|a| a.to_string()),
+        milestones: cfg.milestones,
     })
 }
+
+fn route_response(denom: String, route: TokenRoute) -> RouteResponse {
+    RouteResponse {
+        denom,
+        ra_cw20_addr: route.ra_cw20_addr.to_string(),
+        fee_collector_addr: route.fee_collector_addr.to_string(),
+        rate_num: route.rate_num,
+        rate_den: route.rate_den,
+        reserve_bps: route.reserve_bps,
+        reserve_balance: route.reserve_balance,
+    }
+}
+
+fn query_route(deps: Deps, denom: String) -> StdResult<RouteResponse> {
+    let route = ROUTES.load(deps.storage, denom.clone())?;
+    Ok(route_response(denom, route))
+}
+
+fn query_progress(deps: Deps, address: String) -> StdResult<ProgressResponse> {
+    let addr = deps.api.addr_validate(&address)?;
+    let cumulative = CUMULATIVE.may_load(deps.storage, &addr)?.unwrap_or_default();
+    let earned_badges = BADGES
+        .prefix(&addr)
+        .range(deps.storage, None, None, Order::Ascending)
+        .map(|item| item.map(|(idx, _)| idx))
+        .collect::<StdResult<Vec<_>>>()?;
+    Ok(ProgressResponse {
+        cumulative,
+        earned_badges,
+    })
+}
+
+fn query_routes(deps: Deps) -> StdResult<RoutesResponse> {
+    let routes = ROUTES
+        .range(deps.storage, None, None, Order::Ascending)
+        .map(|item| item.map(|(denom, route)| route_response(denom, route)))
+        .collect::<StdResult<Vec<_>>>()?;
+    Ok(RoutesResponse { routes })
+}
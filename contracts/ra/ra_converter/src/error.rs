@@ -0,0 +1,53 @@
+use cosmwasm_std::StdError;
+use thiserror::Error;
+
+#[derive(Error, Debug, PartialEq)]
+pub enum ContractError {
+    #[error("{0}")]
+    Std(#[from] StdError),
+
+    #[error("unauthorized")]
+    Unauthorized {},
+
+    #[error("no funds of the expected denom were sent")]
+    InvalidFunds {},
+
+    #[error("unsupported denom attached")]
+    UnsupportedDenom {},
+
+    #[error("rate_num and rate_den must not be zero")]
+    InvalidRate {},
+
+    #[error("converted amount rounds down to zero RA")]
+    DustAmount {},
+
+    #[error("minted amount overflows u128")]
+    MintOverflow {},
+
+    #[error("reserve_bps must be between 0 and 10000")]
+    InvalidReserveBps {},
+
+    #[error("reserve does not hold enough native to cover this redemption")]
+    InsufficientReserve {},
+
+    #[error("denom is not registered with this converter")]
+    UnregisteredDenom {},
+
+    #[error("denom is already registered with this converter")]
+    DenomAlreadyRegistered {},
+
+    #[error("ra_cw20_addr is already in use by another route")]
+    Cw20AddrAlreadyRegistered {},
+
+    #[error("nois_proxy_addr is not configured")]
+    NoisProxyNotConfigured {},
+
+    #[error("job_id is already pending")]
+    DuplicateJobId {},
+
+    #[error("job_id is unknown or already completed")]
+    UnknownJobId {},
+
+    #[error("randomness must be exactly 32 bytes")]
+    InvalidRandomness {},
+}
@@ -1,34 +1,99 @@
 use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::{Binary, Uint128};
+use cw20::Cw20ReceiveMsg;
 
 #[cw_serde]
-pub struct InstantiateMsg {
-    /// CW20 contract address for RA token.
-    pub ra_cw20_addr: String,
+pub struct RouteInit {
+    /// The native denom this route accepts (e.g. `uretro`).
+    pub denom: String,
 
-    /// The native denom accepted for conversion (must be `uretro`).
-    pub native_denom: String,
+    /// CW20 contract address for the RA token this denom converts into.
+    pub ra_cw20_addr: String,
 
-    /// Address that receives the full native deposit.
+    /// Address that receives the forwarded (non-reserved) native deposit.
     /// Set this to the chain's fee collector module address so that:
     /// - `x/burn` burns ~80% each block
     /// - remaining ~20% is distributed to stakers
     pub fee_collector_addr: String,
 
-    /// Optional operator who can mint rewards (must still provide native funds).
+    /// RA minted per unit of native is `rate_num / rate_den`. Must have `rate_den != 0`.
+    pub rate_num: Uint128,
+    pub rate_den: Uint128,
+
+    /// Basis points (0-10000) of each conversion's native funds kept in the
+    /// contract as a redemption reserve instead of forwarded to the fee collector.
+    pub reserve_bps: u64,
+}
+
+#[cw_serde]
+pub struct InstantiateMsg {
+    /// Optional operator who can mint rewards and manage routes (must still
+    /// provide native funds for `RewardMint`).
     pub operator: Option<String>,
+
+    /// Routes to register at instantiation; more can be added later via
+    /// `RegisterDenom`.
+    pub routes: Vec<RouteInit>,
+
+    /// Address of the nois-style randomness proxy used by `JackpotConvert`.
+    /// `JackpotConvert` is unavailable unless this is set here; no execute
+    /// message changes it after instantiation.
+    pub nois_proxy_addr: Option<String>,
+
+    /// Maximum jackpot bonus in basis points; the actual bonus is drawn
+    /// uniformly from `[0, max_bonus_bps)` using the beacon randomness.
+    pub max_bonus_bps: u64,
+
+    /// CW721 contract minting achievement badges once a recipient's cumulative
+    /// minted RA crosses a milestone. Leave unset to disable badges.
+    pub nft_contract_addr: Option<String>,
+
+    /// Cumulative minted-RA thresholds (ascending) that each earn one badge.
+    pub milestones: Vec<Uint128>,
+}
+
+/// Minimal mirror of the nois-proxy `ExecuteMsg::GetNextRandomness` variant so
+/// this contract doesn't need the full `nois` crate as a dependency.
+#[cw_serde]
+pub enum ProxyExecuteMsg {
+    GetNextRandomness { job_id: String },
 }
 
 #[cw_serde]
 pub enum ExecuteMsg {
-    /// Convert attached `native_denom` funds 1:1 into RA CW20 and forward funds to fee collector.
+    /// Convert the attached native funds into RA CW20 at the rate configured for
+    /// that denom's route, forwarding the non-reserved portion to the route's
+    /// fee collector.
     Convert {},
 
-    /// Mint RA as a reward. Caller must be `operator` and must attach funds of `native_denom`.
-    /// This still routes the native to fee collector (burn+stakers) and mints RA 1:1.
+    /// Mint RA as a reward. Caller must be `operator` and must attach funds of a
+    /// registered denom. This still routes the native the same way `Convert` does.
     RewardMint { recipient: String },
 
     /// Update operator (only current operator).
     UpdateOperator { operator: Option<String> },
+
+    /// Register a new denom route (only operator). Fails if already registered.
+    RegisterDenom(RouteInit),
+
+    /// Update an existing denom route's CW20 address, fee collector, rate, or
+    /// reserve share (only operator). Fails if the denom isn't registered.
+    /// The route's accrued `reserve_balance` is preserved.
+    UpdateDenom(RouteInit),
+
+    /// Redemption hook: a route's `ra_cw20_addr` calls this after RA is sent to
+    /// this contract via `Cw20ExecuteMsg::Send`. Burns the received RA and pays
+    /// out the equivalent native from that route's reserve to the original sender.
+    Receive(Cw20ReceiveMsg),
+
+    /// Convert attached native funds the same way `Convert` does, but defer
+    /// minting until the nois beacon callback lands and mint a randomized
+    /// bonus on top of the base amount.
+    JackpotConvert {},
+
+    /// Nois proxy callback for a `JackpotConvert` job. Must originate from
+    /// `nois_proxy_addr`.
+    NoisReceive { job_id: String, randomness: Binary },
 }
 
 #[cw_serde]
@@ -36,12 +101,46 @@ pub enum ExecuteMsg {
 pub enum QueryMsg {
     #[returns(ConfigResponse)]
     Config {},
+
+    #[returns(RoutesResponse)]
+    Routes {},
+
+    #[returns(RouteResponse)]
+    Route { denom: String },
+
+    /// A recipient's cumulative minted RA and which milestone badges
+    /// (by index into `Config::milestones`) they've earned.
+    #[returns(ProgressResponse)]
+    Progress { address: String },
+}
+
+#[cw_serde]
+pub struct ProgressResponse {
+    pub cumulative: Uint128,
+    pub earned_badges: Vec<u64>,
 }
 
 #[cw_serde]
 pub struct ConfigResponse {
+    pub operator: Option<String>,
+    pub nois_proxy_addr: Option<String>,
+    pub max_bonus_bps: u64,
+    pub nft_contract_addr: Option<String>,
+    pub milestones: Vec<Uint128>,
+}
+
+#[cw_serde]
+pub struct RouteResponse {
+    pub denom: String,
     pub ra_cw20_addr: String,
-    pub native_denom: String,
     pub fee_collector_addr: String,
-    pub operator: Option<String>,
+    pub rate_num: Uint128,
+    pub rate_den: Uint128,
+    pub reserve_bps: u64,
+    pub reserve_balance: Uint128,
+}
+
+#[cw_serde]
+pub struct RoutesResponse {
+    pub routes: Vec<RouteResponse>,
 }
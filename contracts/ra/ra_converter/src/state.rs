@@ -1,13 +1,64 @@
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::Addr;
-use cw_storage_plus::Item;
+use cosmwasm_std::{Addr, Uint128};
+use cw_storage_plus::{Item, Map};
 
 #[cw_serde]
 pub struct Config {
+    pub operator: Option<Addr>,
+
+    /// Address of the nois-style randomness proxy used by `JackpotConvert`.
+    pub nois_proxy_addr: Option<Addr>,
+
+    /// Maximum jackpot bonus in basis points; the actual bonus is drawn
+    /// uniformly from `[0, max_bonus_bps)` using the beacon randomness.
+    pub max_bonus_bps: u64,
+
+    /// CW721 contract minting achievement badges once a recipient's cumulative
+    /// minted RA (summed across all denoms/routes, after each route's rate is
+    /// applied) crosses a milestone.
+    pub nft_contract_addr: Option<Addr>,
+
+    /// Cumulative minted-RA thresholds (ascending) that each earn one badge.
+    pub milestones: Vec<Uint128>,
+}
+
+/// A `JackpotConvert` awaiting its randomness callback.
+#[cw_serde]
+pub struct PendingJob {
+    pub recipient: Addr,
+    pub ra_cw20_addr: Addr,
+    pub denom: String,
+    pub base_amount: Uint128,
+}
+
+/// Per-denom conversion route: which CW20 it mints/burns, where its fees go,
+/// at what rate, and how much of its native reserve is held by the contract.
+#[cw_serde]
+pub struct TokenRoute {
     pub ra_cw20_addr: Addr,
-    pub native_denom: String,
     pub fee_collector_addr: Addr,
-    pub operator: Option<Addr>,
+
+    /// RA minted per unit of native is `rate_num / rate_den`.
+    pub rate_num: Uint128,
+    pub rate_den: Uint128,
+
+    /// Fraction (in basis points, 0-10000) of each conversion's native funds kept
+    /// in the contract as a redemption reserve instead of forwarded to the fee
+    /// collector.
+    pub reserve_bps: u64,
+
+    /// Native balance set aside for `Receive`-triggered redemptions of this denom.
+    pub reserve_balance: Uint128,
 }
 
 pub const CONFIG: Item<Config> = Item::new("config");
+pub const ROUTES: Map<String, TokenRoute> = Map::new("routes");
+pub const PENDING_JOBS: Map<String, PendingJob> = Map::new("pending_jobs");
+pub const JOB_SEQ: Item<u64> = Item::new("job_seq");
+
+/// Cumulative converted amount per recipient, summed across all denoms, used
+/// to determine milestone badge eligibility.
+pub const CUMULATIVE: Map<&Addr, Uint128> = Map::new("cumulative");
+
+/// Whether `(recipient, milestone_index)` has already had its badge minted.
+pub const BADGES: Map<(&Addr, u64), bool> = Map::new("badges");
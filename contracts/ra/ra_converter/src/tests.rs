@@ -1,16 +1,38 @@
 use cosmwasm_std::{
     coin,
     testing::{mock_dependencies, mock_env, mock_info},
-    Addr, BankMsg, CosmosMsg, WasmMsg,
+    Addr, BankMsg, Binary, CosmosMsg, Uint128, WasmMsg,
 };
-use cw20::Cw20ExecuteMsg;
+use cw20::{Cw20ExecuteMsg, Cw20ReceiveMsg};
 
 use crate::{
     contract,
     error::ContractError,
-    msg::{ExecuteMsg, InstantiateMsg, QueryMsg},
+    msg::{ConfigResponse, ExecuteMsg, InstantiateMsg, QueryMsg, RouteInit, RouteResponse},
 };
 
+fn default_instantiate(routes: Vec<RouteInit>) -> InstantiateMsg {
+    InstantiateMsg {
+        operator: None,
+        routes,
+        nois_proxy_addr: None,
+        max_bonus_bps: 0,
+        nft_contract_addr: None,
+        milestones: vec![],
+    }
+}
+
+fn default_route() -> RouteInit {
+    RouteInit {
+        denom: "uretro".to_string(),
+        ra_cw20_addr: "ratoken".to_string(),
+        fee_collector_addr: "feecollector".to_string(),
+        rate_num: Uint128::new(1),
+        rate_den: Uint128::new(1),
+        reserve_bps: 0,
+    }
+}
+
 fn instantiate_default() -> (
     cosmwasm_std::OwnedDeps<
         cosmwasm_std::testing::MockStorage,
@@ -23,12 +45,7 @@ fn instantiate_default() -> (
     let env = mock_env();
     let info = mock_info("creator", &[]);
 
-    let msg = InstantiateMsg {
-        ra_cw20_addr: "ratoken".to_string(),
-        native_denom: "uretro".to_string(),
-        fee_collector_addr: "feecollector".to_string(),
-        operator: None,
-    };
+    let msg = default_instantiate(vec![default_route()]);
 
     let resp = contract::instantiate(deps.as_mut(), env.clone(), info, msg).unwrap();
     assert_eq!(resp.attributes.iter().any(|a| a.key == "action"), true);
@@ -37,12 +54,12 @@ fn instantiate_default() -> (
 }
 
 #[test]
-fn convert_requires_uretro_funds() {
+fn convert_requires_registered_denom() {
     let (mut deps, env) = instantiate_default();
 
     let info = mock_info("alice", &[coin(123, "ubad")]);
     let err = contract::execute(deps.as_mut(), env.clone(), info, ExecuteMsg::Convert {}).unwrap_err();
-    assert_eq!(err, ContractError::UnsupportedDenom {});
+    assert_eq!(err, ContractError::UnregisteredDenom {});
 
     let info = mock_info("alice", &[]);
     let err = contract::execute(deps.as_mut(), env, info, ExecuteMsg::Convert {}).unwrap_err();
@@ -185,6 +202,482 @@ fn update_operator_changes_authority() {
 
     // config query shows new operator
     let bin = contract::query(deps.as_ref(), env, QueryMsg::Config {}).unwrap();
-    let resp: crate::msg::ConfigResponse = cosmwasm_std::from_json(bin).unwrap();
+    let resp: ConfigResponse = cosmwasm_std::from_json(bin).unwrap();
     assert_eq!(resp.operator, Some(Addr::unchecked("newop").to_string()));
 }
+
+#[test]
+fn config_query_reports_badge_and_milestone_settings() {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+    let info = mock_info("creator", &[]);
+
+    let mut msg = default_instantiate(vec![default_route()]);
+    msg.nft_contract_addr = Some("badgenft".to_string());
+    msg.milestones = vec![Uint128::new(5_000), Uint128::new(10_000)];
+    contract::instantiate(deps.as_mut(), env.clone(), info, msg).unwrap();
+
+    let bin = contract::query(deps.as_ref(), env, QueryMsg::Config {}).unwrap();
+    let resp: ConfigResponse = cosmwasm_std::from_json(bin).unwrap();
+    assert_eq!(resp.nft_contract_addr, Some("badgenft".to_string()));
+    assert_eq!(resp.milestones, vec![Uint128::new(5_000), Uint128::new(10_000)]);
+}
+
+#[test]
+fn convert_applies_configured_rate() {
+    let (mut deps, env) = instantiate_default();
+
+    let info = mock_info("creator", &[]);
+    let mut route = default_route();
+    route.rate_num = Uint128::new(3);
+    route.rate_den = Uint128::new(2);
+    contract::execute(deps.as_mut(), env.clone(), info, ExecuteMsg::UpdateDenom(route)).unwrap();
+
+    let info = mock_info("alice", &[coin(10_000, "uretro")]);
+    let resp = contract::execute(deps.as_mut(), env, info, ExecuteMsg::Convert {}).unwrap();
+
+    match &resp.messages[1].msg {
+        CosmosMsg::Wasm(WasmMsg::Execute { msg, .. }) => {
+            let parsed: Cw20ExecuteMsg = cosmwasm_std::from_json(msg).unwrap();
+            assert_eq!(
+                parsed,
+                Cw20ExecuteMsg::Mint {
+                    recipient: "alice".to_string(),
+                    amount: 15_000u128.into(),
+                }
+            );
+        }
+        other => panic!("unexpected msg1: {other:?}"),
+    }
+}
+
+#[test]
+fn convert_rejects_dust_below_one_ra_unit() {
+    let (mut deps, env) = instantiate_default();
+
+    let info = mock_info("creator", &[]);
+    let mut route = default_route();
+    route.rate_num = Uint128::new(1);
+    route.rate_den = Uint128::new(1_000);
+    contract::execute(deps.as_mut(), env.clone(), info, ExecuteMsg::UpdateDenom(route)).unwrap();
+
+    let info = mock_info("alice", &[coin(1, "uretro")]);
+    let err = contract::execute(deps.as_mut(), env, info, ExecuteMsg::Convert {}).unwrap_err();
+    assert_eq!(err, ContractError::DustAmount {});
+}
+
+#[test]
+fn instantiate_rejects_zero_rate_den() {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+    let info = mock_info("creator", &[]);
+
+    let mut route = default_route();
+    route.rate_den = Uint128::zero();
+
+    let msg = default_instantiate(vec![route]);
+
+    let err = contract::instantiate(deps.as_mut(), env, info, msg).unwrap_err();
+    assert_eq!(err, ContractError::InvalidRate {});
+}
+
+#[test]
+fn instantiate_rejects_zero_rate_num() {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+    let info = mock_info("creator", &[]);
+
+    let mut route = default_route();
+    route.rate_num = Uint128::zero();
+
+    let msg = default_instantiate(vec![route]);
+
+    let err = contract::instantiate(deps.as_mut(), env, info, msg).unwrap_err();
+    assert_eq!(err, ContractError::InvalidRate {});
+}
+
+#[test]
+fn update_denom_rejects_zero_rate_num() {
+    let (mut deps, env) = instantiate_default();
+    let info = mock_info("creator", &[]);
+
+    let mut route = default_route();
+    route.rate_num = Uint128::zero();
+
+    let err = contract::execute(deps.as_mut(), env, info, ExecuteMsg::UpdateDenom(route)).unwrap_err();
+    assert_eq!(err, ContractError::InvalidRate {});
+}
+
+#[test]
+fn convert_keeps_reserve_share_and_redeems_via_receive() {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+    let info = mock_info("creator", &[]);
+
+    let mut route = default_route();
+    route.reserve_bps = 5_000;
+
+    let msg = default_instantiate(vec![route]);
+    contract::instantiate(deps.as_mut(), env.clone(), info, msg).unwrap();
+
+    let info = mock_info("alice", &[coin(10_000, "uretro")]);
+    let resp = contract::execute(deps.as_mut(), env.clone(), info, ExecuteMsg::Convert {}).unwrap();
+
+    match &resp.messages[0].msg {
+        CosmosMsg::Bank(BankMsg::Send { to_address, amount }) => {
+            assert_eq!(to_address, "feecollector");
+            assert_eq!(amount, &vec![coin(5_000, "uretro")]);
+        }
+        other => panic!("unexpected msg0: {other:?}"),
+    }
+
+    let receive = Cw20ReceiveMsg {
+        sender: "alice".to_string(),
+        amount: Uint128::new(3_000),
+        msg: Binary::default(),
+    };
+    let info = mock_info("ratoken", &[]);
+    let resp = contract::execute(
+        deps.as_mut(),
+        env.clone(),
+        info,
+        ExecuteMsg::Receive(receive),
+    )
+    .unwrap();
+
+    match &resp.messages[1].msg {
+        CosmosMsg::Bank(BankMsg::Send { to_address, amount }) => {
+            assert_eq!(to_address, "alice");
+            assert_eq!(amount, &vec![coin(3_000, "uretro")]);
+        }
+        other => panic!("unexpected msg1: {other:?}"),
+    }
+
+    // draining more than the remaining reserve (2,000 left) fails
+    let receive = Cw20ReceiveMsg {
+        sender: "alice".to_string(),
+        amount: Uint128::new(2_001),
+        msg: Binary::default(),
+    };
+    let info = mock_info("ratoken", &[]);
+    let err = contract::execute(deps.as_mut(), env, info, ExecuteMsg::Receive(receive))
+        .unwrap_err();
+    assert_eq!(err, ContractError::InsufficientReserve {});
+}
+
+#[test]
+fn receive_rejects_sender_other_than_ra_cw20() {
+    let (mut deps, env) = instantiate_default();
+
+    let receive = Cw20ReceiveMsg {
+        sender: "alice".to_string(),
+        amount: Uint128::new(1),
+        msg: Binary::default(),
+    };
+    let info = mock_info("not_ratoken", &[]);
+    let err = contract::execute(deps.as_mut(), env, info, ExecuteMsg::Receive(receive))
+        .unwrap_err();
+    assert_eq!(err, ContractError::Unauthorized {});
+}
+
+#[test]
+fn register_denom_rejects_duplicate_and_unauthorized() {
+    let (mut deps, env) = instantiate_default();
+
+    let info = mock_info("not_creator", &[]);
+    let mut route = default_route();
+    route.denom = "ushmup".to_string();
+    let err = contract::execute(
+        deps.as_mut(),
+        env.clone(),
+        info,
+        ExecuteMsg::RegisterDenom(route.clone()),
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::Unauthorized {});
+
+    let info = mock_info("creator", &[]);
+    let err = contract::execute(
+        deps.as_mut(),
+        env,
+        info,
+        ExecuteMsg::RegisterDenom(default_route()),
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::DenomAlreadyRegistered {});
+}
+
+#[test]
+fn multiple_routes_are_independent() {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+    let info = mock_info("creator", &[]);
+
+    let msg = default_instantiate(vec![default_route()]);
+    contract::instantiate(deps.as_mut(), env.clone(), info, msg).unwrap();
+
+    let info = mock_info("creator", &[]);
+    let mut second = default_route();
+    second.denom = "ushmup".to_string();
+    second.ra_cw20_addr = "shmuptoken".to_string();
+    second.rate_num = Uint128::new(2);
+    contract::execute(deps.as_mut(), env.clone(), info, ExecuteMsg::RegisterDenom(second)).unwrap();
+
+    let bin = contract::query(deps.as_ref(), env.clone(), QueryMsg::Routes {}).unwrap();
+    let resp: crate::msg::RoutesResponse = cosmwasm_std::from_json(bin).unwrap();
+    assert_eq!(resp.routes.len(), 2);
+
+    let bin = contract::query(
+        deps.as_ref(),
+        env,
+        QueryMsg::Route {
+            denom: "ushmup".to_string(),
+        },
+    )
+    .unwrap();
+    let resp: RouteResponse = cosmwasm_std::from_json(bin).unwrap();
+    assert_eq!(resp.ra_cw20_addr, "shmuptoken");
+    assert_eq!(resp.rate_num, Uint128::new(2));
+}
+
+#[test]
+fn register_denom_rejects_reused_ra_cw20_addr() {
+    let (mut deps, env) = instantiate_default();
+    let info = mock_info("creator", &[]);
+
+    let mut second = default_route();
+    second.denom = "ushmup".to_string();
+
+    let err = contract::execute(deps.as_mut(), env, info, ExecuteMsg::RegisterDenom(second)).unwrap_err();
+    assert_eq!(err, ContractError::Cw20AddrAlreadyRegistered {});
+}
+
+#[test]
+fn update_denom_rejects_ra_cw20_addr_owned_by_another_route() {
+    let (mut deps, env) = instantiate_default();
+    let info = mock_info("creator", &[]);
+
+    let mut second = default_route();
+    second.denom = "ushmup".to_string();
+    second.ra_cw20_addr = "shmuptoken".to_string();
+    contract::execute(
+        deps.as_mut(),
+        env.clone(),
+        info.clone(),
+        ExecuteMsg::RegisterDenom(second.clone()),
+    )
+    .unwrap();
+
+    // Retargeting "ushmup" at the cw20 already backing "uretro" must be rejected.
+    second.ra_cw20_addr = "ratoken".to_string();
+    let err =
+        contract::execute(deps.as_mut(), env, info, ExecuteMsg::UpdateDenom(second)).unwrap_err();
+    assert_eq!(err, ContractError::Cw20AddrAlreadyRegistered {});
+}
+
+#[test]
+fn jackpot_convert_requires_nois_proxy_configured() {
+    let (mut deps, env) = instantiate_default();
+
+    let info = mock_info("alice", &[coin(10_000, "uretro")]);
+    let err =
+        contract::execute(deps.as_mut(), env, info, ExecuteMsg::JackpotConvert {}).unwrap_err();
+    assert_eq!(err, ContractError::NoisProxyNotConfigured {});
+}
+
+#[test]
+fn jackpot_convert_requests_randomness_and_receive_mints_boosted_amount() {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+    let info = mock_info("creator", &[]);
+
+    let mut msg = default_instantiate(vec![default_route()]);
+    msg.nois_proxy_addr = Some("noisproxy".to_string());
+    msg.max_bonus_bps = 5_000;
+    contract::instantiate(deps.as_mut(), env.clone(), info, msg).unwrap();
+
+    let info = mock_info("alice", &[coin(10_000, "uretro")]);
+    let resp = contract::execute(
+        deps.as_mut(),
+        env.clone(),
+        info,
+        ExecuteMsg::JackpotConvert {},
+    )
+    .unwrap();
+
+    let job_id = resp
+        .attributes
+        .iter()
+        .find(|a| a.key == "job_id")
+        .unwrap()
+        .value
+        .clone();
+
+    match &resp.messages[1].msg {
+        CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr, msg, ..
+        }) => {
+            assert_eq!(contract_addr, "noisproxy");
+            let parsed: crate::msg::ProxyExecuteMsg = cosmwasm_std::from_json(msg).unwrap();
+            assert_eq!(
+                parsed,
+                crate::msg::ProxyExecuteMsg::GetNextRandomness {
+                    job_id: job_id.clone()
+                }
+            );
+        }
+        other => panic!("unexpected msg1: {other:?}"),
+    }
+
+    // randomness with first 8 bytes = 2000 (LE) -> bonus_bps = 2000 % 5000 = 2000
+    let mut randomness = vec![0u8; 32];
+    randomness[0..8].copy_from_slice(&2_000u64.to_le_bytes());
+    let info = mock_info("not_noisproxy", &[]);
+    let err = contract::execute(
+        deps.as_mut(),
+        env.clone(),
+        info,
+        ExecuteMsg::NoisReceive {
+            job_id: job_id.clone(),
+            randomness: Binary::from(randomness.clone()),
+        },
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::Unauthorized {});
+
+    let info = mock_info("noisproxy", &[]);
+    let resp = contract::execute(
+        deps.as_mut(),
+        env.clone(),
+        info,
+        ExecuteMsg::NoisReceive {
+            job_id: job_id.clone(),
+            randomness: Binary::from(randomness),
+        },
+    )
+    .unwrap();
+
+    match &resp.messages[0].msg {
+        CosmosMsg::Wasm(WasmMsg::Execute { msg, .. }) => {
+            let parsed: Cw20ExecuteMsg = cosmwasm_std::from_json(msg).unwrap();
+            assert_eq!(
+                parsed,
+                Cw20ExecuteMsg::Mint {
+                    recipient: "alice".to_string(),
+                    amount: 12_000u128.into(),
+                }
+            );
+        }
+        other => panic!("unexpected msg0: {other:?}"),
+    }
+
+    // replaying the same job_id fails once consumed
+    let info = mock_info("noisproxy", &[]);
+    let err = contract::execute(
+        deps.as_mut(),
+        env,
+        info,
+        ExecuteMsg::NoisReceive {
+            job_id,
+            randomness: Binary::from(vec![0u8; 32]),
+        },
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::UnknownJobId {});
+}
+
+#[test]
+fn convert_mints_badge_on_crossing_milestone() {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+    let info = mock_info("creator", &[]);
+
+    let mut msg = default_instantiate(vec![default_route()]);
+    msg.nft_contract_addr = Some("badgenft".to_string());
+    msg.milestones = vec![Uint128::new(5_000), Uint128::new(10_000)];
+    contract::instantiate(deps.as_mut(), env.clone(), info, msg).unwrap();
+
+    // first convert of 6,000 crosses the 5,000 milestone only
+    let info = mock_info("alice", &[coin(6_000, "uretro")]);
+    let resp = contract::execute(deps.as_mut(), env.clone(), info, ExecuteMsg::Convert {}).unwrap();
+    assert_eq!(resp.messages.len(), 3);
+    match &resp.messages[2].msg {
+        CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr, msg, ..
+        }) => {
+            assert_eq!(contract_addr, "badgenft");
+            let parsed: cw721_base::msg::ExecuteMsg<cw721_base::Extension, cosmwasm_std::Empty> =
+                cosmwasm_std::from_json(msg).unwrap();
+            match parsed {
+                cw721_base::msg::ExecuteMsg::Mint { token_id, owner, .. } => {
+                    assert_eq!(token_id, "alice-milestone-0");
+                    assert_eq!(owner, "alice");
+                }
+                other => panic!("unexpected cw721 msg: {other:?}"),
+            }
+        }
+        other => panic!("unexpected msg2: {other:?}"),
+    }
+
+    let bin = contract::query(
+        deps.as_ref(),
+        env.clone(),
+        QueryMsg::Progress {
+            address: "alice".to_string(),
+        },
+    )
+    .unwrap();
+    let resp: crate::msg::ProgressResponse = cosmwasm_std::from_json(bin).unwrap();
+    assert_eq!(resp.cumulative, Uint128::new(6_000));
+    assert_eq!(resp.earned_badges, vec![0]);
+
+    // second convert of 5,000 crosses the 10,000 milestone; the first badge is
+    // not minted again
+    let info = mock_info("alice", &[coin(5_000, "uretro")]);
+    let resp = contract::execute(deps.as_mut(), env, info, ExecuteMsg::Convert {}).unwrap();
+    let badge_mints = resp
+        .messages
+        .iter()
+        .filter(|m| matches!(&m.msg, CosmosMsg::Wasm(WasmMsg::Execute { contract_addr, .. }) if contract_addr == "badgenft"))
+        .count();
+    assert_eq!(badge_mints, 1);
+}
+
+#[test]
+fn milestone_progress_tracks_minted_ra_not_raw_native_across_routes() {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+    let info = mock_info("creator", &[]);
+
+    let mut lopsided = default_route();
+    lopsided.denom = "ulopsided".to_string();
+    lopsided.ra_cw20_addr = "lopsidedtoken".to_string();
+    lopsided.rate_num = Uint128::new(1);
+    lopsided.rate_den = Uint128::new(1_000);
+
+    let mut msg = default_instantiate(vec![default_route(), lopsided]);
+    msg.nft_contract_addr = Some("badgenft".to_string());
+    msg.milestones = vec![Uint128::new(5_000)];
+    contract::instantiate(deps.as_mut(), env.clone(), info, msg).unwrap();
+
+    // A huge deposit of the poorly-rated denom mints very little RA and must
+    // not move the recipient any closer to the milestone than that RA amount.
+    let info = mock_info("alice", &[coin(1_000_000, "ulopsided")]);
+    let resp = contract::execute(deps.as_mut(), env.clone(), info, ExecuteMsg::Convert {}).unwrap();
+    assert!(resp
+        .messages
+        .iter()
+        .all(|m| !matches!(&m.msg, CosmosMsg::Wasm(WasmMsg::Execute { contract_addr, .. }) if contract_addr == "badgenft")));
+
+    let bin = contract::query(
+        deps.as_ref(),
+        env,
+        QueryMsg::Progress {
+            address: "alice".to_string(),
+        },
+    )
+    .unwrap();
+    let resp: crate::msg::ProgressResponse = cosmwasm_std::from_json(bin).unwrap();
+    assert_eq!(resp.cumulative, Uint128::new(1_000));
+    assert!(resp.earned_badges.is_empty());
+}
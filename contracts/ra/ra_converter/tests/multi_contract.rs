@@ -0,0 +1,204 @@
+//! End-to-end coverage of the converter wired up to the *real* `ra_cw20` and
+//! `ra_cw721` contracts via `cw-multi-test`, instead of mocked dependencies.
+
+use cosmwasm_std::{coin, Addr, Empty, Uint128};
+use cw_multi_test::{App, AppBuilder, Contract, ContractWrapper, Executor};
+
+use ra_converter::msg::{ExecuteMsg, InstantiateMsg, RouteInit};
+
+const ADMIN: &str = "admin";
+const USER: &str = "user";
+const FEE_COLLECTOR: &str = "feecollector";
+const NATIVE_DENOM: &str = "uretro";
+const OTHER_DENOM: &str = "ubad";
+
+fn ra_cw20_contract() -> Box<dyn Contract<Empty>> {
+    Box::new(ContractWrapper::new(
+        ra_cw20::entry::execute,
+        ra_cw20::entry::instantiate,
+        ra_cw20::entry::query,
+    ))
+}
+
+fn ra_cw721_contract() -> Box<dyn Contract<Empty>> {
+    Box::new(ContractWrapper::new(
+        ra_cw721::entry::execute,
+        ra_cw721::entry::instantiate,
+        ra_cw721::entry::query,
+    ))
+}
+
+fn ra_converter_contract() -> Box<dyn Contract<Empty>> {
+    Box::new(ContractWrapper::new(
+        ra_converter::contract::execute,
+        ra_converter::contract::instantiate,
+        ra_converter::contract::query,
+    ))
+}
+
+struct TestEnv {
+    app: App,
+    ra_cw20_addr: Addr,
+    #[allow(dead_code)]
+    ra_cw721_addr: Addr,
+    converter_addr: Addr,
+}
+
+/// Instantiates all three contracts (converter, RA cw20, RA badge cw721) with
+/// the converter wired up as the cw20/cw721 minter, funds `USER` with native,
+/// and registers the `uretro` route.
+fn setup() -> TestEnv {
+    let mut app = AppBuilder::new().build(|router, _api, storage| {
+        router
+            .bank
+            .init_balance(
+                storage,
+                &Addr::unchecked(USER),
+                vec![coin(1_000_000, NATIVE_DENOM), coin(1_000_000, OTHER_DENOM)],
+            )
+            .unwrap();
+    });
+
+    let cw20_code_id = app.store_code(ra_cw20_contract());
+    let cw721_code_id = app.store_code(ra_cw721_contract());
+    let converter_code_id = app.store_code(ra_converter_contract());
+
+    let converter_addr = app
+        .instantiate_contract(
+            converter_code_id,
+            Addr::unchecked(ADMIN),
+            &InstantiateMsg {
+                operator: Some(ADMIN.to_string()),
+                routes: vec![],
+                nois_proxy_addr: None,
+                max_bonus_bps: 0,
+                nft_contract_addr: None,
+                milestones: vec![],
+            },
+            &[],
+            "converter",
+            None,
+        )
+        .unwrap();
+
+    let ra_cw20_addr = app
+        .instantiate_contract(
+            cw20_code_id,
+            Addr::unchecked(ADMIN),
+            &cw20_base::msg::InstantiateMsg {
+                name: "RetroArcade".to_string(),
+                symbol: "RA".to_string(),
+                decimals: 6,
+                initial_balances: vec![],
+                mint: Some(cw20::MinterResponse {
+                    minter: converter_addr.to_string(),
+                    cap: None,
+                }),
+                marketing: None,
+            },
+            &[],
+            "ra-cw20",
+            None,
+        )
+        .unwrap();
+
+    let ra_cw721_addr = app
+        .instantiate_contract(
+            cw721_code_id,
+            Addr::unchecked(ADMIN),
+            &cw721_base::msg::InstantiateMsg {
+                name: "RetroArcade Badges".to_string(),
+                symbol: "RABADGE".to_string(),
+                minter: converter_addr.to_string(),
+            },
+            &[],
+            "ra-cw721",
+            None,
+        )
+        .unwrap();
+
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        converter_addr.clone(),
+        &ExecuteMsg::RegisterDenom(RouteInit {
+            denom: NATIVE_DENOM.to_string(),
+            ra_cw20_addr: ra_cw20_addr.to_string(),
+            fee_collector_addr: FEE_COLLECTOR.to_string(),
+            rate_num: Uint128::new(1),
+            rate_den: Uint128::new(1),
+            reserve_bps: 0,
+        }),
+        &[],
+    )
+    .unwrap();
+
+    TestEnv {
+        app,
+        ra_cw20_addr,
+        ra_cw721_addr,
+        converter_addr,
+    }
+}
+
+#[test]
+fn convert_forwards_native_and_mints_real_ra() {
+    let mut env = setup();
+
+    env.app
+        .execute_contract(
+            Addr::unchecked(USER),
+            env.converter_addr.clone(),
+            &ExecuteMsg::Convert {},
+            &[coin(10_000, NATIVE_DENOM)],
+        )
+        .unwrap();
+
+    let fee_balance = env.app.wrap().query_balance(FEE_COLLECTOR, NATIVE_DENOM).unwrap();
+    assert_eq!(fee_balance.amount, Uint128::new(10_000));
+
+    let ra_balance: cw20::BalanceResponse = env
+        .app
+        .wrap()
+        .query_wasm_smart(
+            env.ra_cw20_addr.clone(),
+            &cw20_base::msg::QueryMsg::Balance {
+                address: USER.to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(ra_balance.balance, Uint128::new(10_000));
+}
+
+#[test]
+fn reward_mint_rejects_unauthorized_caller() {
+    let mut env = setup();
+
+    let err = env
+        .app
+        .execute_contract(
+            Addr::unchecked(USER),
+            env.converter_addr.clone(),
+            &ExecuteMsg::RewardMint {
+                recipient: USER.to_string(),
+            },
+            &[coin(1, NATIVE_DENOM)],
+        )
+        .unwrap_err();
+    assert!(err.root_cause().to_string().contains("unauthorized"));
+}
+
+#[test]
+fn convert_rejects_unregistered_denom() {
+    let mut env = setup();
+
+    let err = env
+        .app
+        .execute_contract(
+            Addr::unchecked(USER),
+            env.converter_addr.clone(),
+            &ExecuteMsg::Convert {},
+            &[coin(1, OTHER_DENOM)],
+        )
+        .unwrap_err();
+    assert!(err.root_cause().to_string().contains("not registered"));
+}
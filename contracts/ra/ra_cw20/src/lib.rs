@@ -9,7 +9,7 @@ pub type QueryMsg = cw20_base::msg::QueryMsg;
 pub type MigrateMsg = cw20_base::msg::MigrateMsg;
 
 #[cfg(not(feature = "library"))]
-mod entry {
+pub mod entry {
     use super::*;
     use cosmwasm_std::{entry_point, Binary, Deps, DepsMut, Env, MessageInfo, Response, StdResult};
 
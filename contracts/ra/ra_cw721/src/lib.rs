@@ -8,7 +8,7 @@ pub type ExecuteMsg = cw721_base::msg::ExecuteMsg<cw721_base::Extension, cosmwas
 pub type QueryMsg = cw721_base::msg::QueryMsg<cosmwasm_std::Empty>;
 
 #[cfg(not(feature = "library"))]
-mod entry {
+pub mod entry {
     use super::*;
     use cosmwasm_std::{entry_point, Binary, Deps, DepsMut, Env, MessageInfo, Response, StdResult};
 